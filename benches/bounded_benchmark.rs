@@ -0,0 +1,26 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use static_slicing::*;
+
+fn repeated_wrapped_index(d: &[u8; 8]) -> u8 {
+	let x = SliceWrapper::new(d);
+	x[StaticIndex::<0>]
+		.wrapping_add(x[StaticIndex::<2>])
+		.wrapping_add(x[StaticIndex::<4>])
+		.wrapping_add(x[StaticIndex::<6>])
+}
+
+fn repeated_bounded_index(d: &[u8; 8]) -> u8 {
+	let x = SliceWrapper::new(d).expect_len::<8>();
+	x[StaticIndex::<0>]
+		.wrapping_add(x[StaticIndex::<2>])
+		.wrapping_add(x[StaticIndex::<4>])
+		.wrapping_add(x[StaticIndex::<6>])
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    c.bench_function("repeated runtime-checked wrapped index", |b| b.iter(|| repeated_wrapped_index(&black_box([5u8; 8]))));
+    c.bench_function("repeated zero-cost bounded index", |b| b.iter(|| repeated_bounded_index(&black_box([5u8; 8]))));
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -1,12 +1,12 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use static_slicing::*;
 
-fn potentially_panicking_index<'a>(d: &'a [u8; 8]) -> &'a [u8; 4] {
+fn potentially_panicking_index(d: &[u8; 8]) -> &[u8; 4] {
 	let tmp = &d[4..8];
 	tmp.try_into().unwrap()
 }
 
-fn compile_checked_index<'a>(d: &'a [u8; 8]) -> &'a [u8; 4] {
+fn compile_checked_index(d: &[u8; 8]) -> &[u8; 4] {
 	&d[StaticRangeIndex::<4, 4>]
 }
 
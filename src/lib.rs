@@ -81,7 +81,7 @@ impl<const INDEX: usize, const N: usize, T> Index<StaticIndex<INDEX>> for [T; N]
         let _ = <[T; N] as IsValidIndex<INDEX>>::RESULT;
 
         // SAFETY: We've verified bounds at compile time.
-        unsafe { &*(self.as_ptr().add(INDEX) as *const T) }
+        unsafe { &*self.as_ptr().add(INDEX) }
     }
 }
 
@@ -90,7 +90,7 @@ impl<const INDEX: usize, const N: usize, T> IndexMut<StaticIndex<INDEX>> for [T;
         let _ = <[T; N] as IsValidIndex<INDEX>>::RESULT;
 
         // SAFETY: We've verified bounds at compile time.
-        unsafe { &mut *(self.as_mut_ptr().add(INDEX) as *mut T) }
+        unsafe { &mut *self.as_mut_ptr().add(INDEX) }
     }
 }
 
@@ -144,11 +144,144 @@ impl<const START: usize, const LENGTH: usize, const N: usize, T>
     }
 }
 
+/// Returns a reference to the element at `INDEX` in `arr`, verified at compile time.
+///
+/// Unlike the [`Index`] impl for [`StaticIndex`], this is a `const fn`, so it can be called from
+/// `const` initializers and other `const fn` bodies, where the `Index` trait (not yet `const` on
+/// stable Rust) can't be used.
+pub const fn static_get<const INDEX: usize, const N: usize, T>(arr: &[T; N]) -> &T {
+    let _ = <[T; N] as IsValidIndex<INDEX>>::RESULT;
+
+    // SAFETY: We've verified bounds at compile time.
+    unsafe { &*(arr.as_ptr().add(INDEX)) }
+}
+
+/// Returns a mutable reference to the element at `INDEX` in `arr`, verified at compile time.
+///
+/// This is the `const fn` counterpart to [`static_get`] for mutable references.
+pub const fn static_get_mut<const INDEX: usize, const N: usize, T>(arr: &mut [T; N]) -> &mut T {
+    let _ = <[T; N] as IsValidIndex<INDEX>>::RESULT;
+
+    // SAFETY: We've verified bounds at compile time.
+    unsafe { &mut *(arr.as_mut_ptr().add(INDEX)) }
+}
+
+/// Returns a reference to the `LENGTH`-element slice of `arr` starting at `START`, verified at
+/// compile time.
+///
+/// Unlike the [`Index`] impl for [`StaticRangeIndex`], this is a `const fn`, so it can be called
+/// from `const` initializers and other `const fn` bodies, where the `Index` trait (not yet
+/// `const` on stable Rust) can't be used.
+pub const fn static_get_range<const START: usize, const LENGTH: usize, const N: usize, T>(
+    arr: &[T; N],
+) -> &[T; LENGTH] {
+    let _ = <[T; N] as IsValidIndexRange<START, LENGTH>>::RESULT;
+
+    // SAFETY: We've verified bounds at compile time.
+    unsafe { &*(arr.as_ptr().add(START) as *const [T; LENGTH]) }
+}
+
+/// Returns a mutable reference to the `LENGTH`-element slice of `arr` starting at `START`,
+/// verified at compile time.
+///
+/// This is the `const fn` counterpart to [`static_get_range`] for mutable references.
+pub const fn static_get_range_mut<const START: usize, const LENGTH: usize, const N: usize, T>(
+    arr: &mut [T; N],
+) -> &mut [T; LENGTH] {
+    let _ = <[T; N] as IsValidIndexRange<START, LENGTH>>::RESULT;
+
+    // SAFETY: We've verified bounds at compile time.
+    unsafe { &mut *(arr.as_mut_ptr().add(START) as *mut [T; LENGTH]) }
+}
+
+/// Internal helper trait for static splitting.
+///
+/// [`IsValidSplit::RESULT`] must evaluate to `()` if `MID + REST` accounts for every element,
+/// or panic otherwise.
+trait IsValidSplit<const MID: usize, const REST: usize> {
+    const RESULT: ();
+}
+
+impl<const MID: usize, const REST: usize, const N: usize, T> IsValidSplit<MID, REST> for [T; N] {
+    const RESULT: () = {
+        if MID + REST != N {
+            panic!("Split point and remainder length don't add up to the array's length!");
+        }
+    };
+}
+
+/// Splits a fixed-size array into a head and a tail, with both lengths verified at compile time.
+///
+/// This is analogous to [`slice::split_at`], but splits `[T; N]` into a `[T; MID]` and a
+/// `[T; REST]` rather than two dynamically-sized slices, so callers keep the compile-time-sized
+/// types that distinguish this crate from plain slicing. `MID + REST` must equal `N`, or the
+/// split fails to compile.
+pub trait StaticSplitAt<T> {
+    fn static_split_at<const MID: usize, const REST: usize>(&self) -> (&[T; MID], &[T; REST]);
+    fn static_split_at_mut<const MID: usize, const REST: usize>(
+        &mut self,
+    ) -> (&mut [T; MID], &mut [T; REST]);
+}
+
+impl<T, const N: usize> StaticSplitAt<T> for [T; N] {
+    fn static_split_at<const MID: usize, const REST: usize>(&self) -> (&[T; MID], &[T; REST]) {
+        let _ = <[T; N] as IsValidSplit<MID, REST>>::RESULT;
+
+        // SAFETY: We've verified at compile time that the head and tail, taken together,
+        //         account for every element of `self` without overlapping.
+        unsafe {
+            (
+                &*(self.as_ptr() as *const [T; MID]),
+                &*(self.as_ptr().add(MID) as *const [T; REST]),
+            )
+        }
+    }
+
+    fn static_split_at_mut<const MID: usize, const REST: usize>(
+        &mut self,
+    ) -> (&mut [T; MID], &mut [T; REST]) {
+        let _ = <[T; N] as IsValidSplit<MID, REST>>::RESULT;
+
+        // SAFETY: We've verified at compile time that the head and tail, taken together,
+        //         account for every element of `self` without overlapping.
+        unsafe {
+            (
+                &mut *(self.as_mut_ptr() as *mut [T; MID]),
+                &mut *(self.as_mut_ptr().add(MID) as *mut [T; REST]),
+            )
+        }
+    }
+}
+
+/// Swaps two elements of a fixed-size array, with both indices verified at compile time.
+///
+/// This is analogous to [`slice::swap`], but the indices are checked against `N` at compile
+/// time rather than at runtime, so no bounds check is emitted at the swap site. `A == B` is
+/// allowed, and is a no-op, just like [`slice::swap`].
+pub trait StaticSwap {
+    fn static_swap<const A: usize, const B: usize>(&mut self);
+}
+
+impl<T, const N: usize> StaticSwap for [T; N] {
+    fn static_swap<const A: usize, const B: usize>(&mut self) {
+        let _ = <[T; N] as IsValidIndex<A>>::RESULT;
+        let _ = <[T; N] as IsValidIndex<B>>::RESULT;
+
+        let ptr = self.as_mut_ptr();
+
+        // SAFETY: We've verified bounds at compile time, and `core::ptr::swap` tolerates
+        //         `A == B`.
+        unsafe {
+            core::ptr::swap(ptr.add(A), ptr.add(B));
+        }
+    }
+}
+
 /// Wrapper around slice references to add support for
 /// the static index types.
-/// 
+///
 /// Due to language weirdness, we can't implement Index(Mut)
-/// for both \[T\] and \[T; N\]. As a result, we need this 
+/// for both \[T\] and \[T; N\]. As a result, we need this
 /// wrapper type.
 #[repr(transparent)]
 pub struct SliceWrapper<'a, I, T>(
@@ -170,6 +303,54 @@ impl<'a, I, T> SliceWrapper<'a, I, T> where T: AsRef<[I]> {
     pub fn new(data: T) -> Self {
         Self(data, PhantomData, PhantomData)
     }
+
+    /// Returns the element at `INDEX`, or `None` if the wrapped slice is too short.
+    ///
+    /// Mirrors [`slice::get`], letting callers handle a too-short buffer as ordinary control
+    /// flow instead of a panic.
+    pub fn get<const INDEX: usize>(&self) -> Option<&I> {
+        self.0.as_ref().get(INDEX)
+    }
+
+    /// Returns the `LENGTH`-element slice starting at `START`, or `None` if the wrapped slice
+    /// is too short.
+    ///
+    /// Mirrors [`slice::get`], but reinterprets the in-range case as a fixed-size `[I; LENGTH]`,
+    /// just like [`Index<StaticRangeIndex<START, LENGTH>>`](Index) does for the panicking path.
+    pub fn get_range<const START: usize, const LENGTH: usize>(&self) -> Option<&[I; LENGTH]> {
+        let inner: &[I] = self.0.as_ref();
+
+        if inner.len() <= START || inner.len() - START < LENGTH {
+            return None;
+        }
+
+        // SAFETY: We've verified bounds above.
+        Some(unsafe { &*(inner.as_ptr().add(START) as *const [I; LENGTH]) })
+    }
+}
+
+impl<'a, I, T> SliceWrapper<'a, I, T>
+where
+    T: AsRef<[I]> + AsMut<[I]>,
+{
+    /// Mutable counterpart to [`SliceWrapper::get`].
+    pub fn get_mut<const INDEX: usize>(&mut self) -> Option<&mut I> {
+        self.0.as_mut().get_mut(INDEX)
+    }
+
+    /// Mutable counterpart to [`SliceWrapper::get_range`].
+    pub fn get_range_mut<const START: usize, const LENGTH: usize>(
+        &mut self,
+    ) -> Option<&mut [I; LENGTH]> {
+        let inner: &mut [I] = self.0.as_mut();
+
+        if inner.len() <= START || inner.len() - START < LENGTH {
+            return None;
+        }
+
+        // SAFETY: We've verified bounds above.
+        Some(unsafe { &mut *(inner.as_mut_ptr().add(START) as *mut [I; LENGTH]) })
+    }
 }
 
 impl<const START: usize, const LENGTH: usize, I, S: AsRef<[I]>> Index<StaticRangeIndex<START, LENGTH>> for SliceWrapper<'_, I, S> {
@@ -212,6 +393,171 @@ impl<const INDEX: usize, I, S: AsRef<[I]> + AsMut<[I]>> IndexMut<StaticIndex<IND
     }
 }
 
+/// A [`SliceWrapper`] whose backing length has been proven, once, to be at least `N`.
+///
+/// Unlike [`SliceWrapper`] itself, indexing a `Bounded` never re-checks the length at runtime:
+/// the length proof is carried in the type via the const generic `N`, so
+/// [`StaticIndex`]/[`StaticRangeIndex`] accesses are verified entirely at compile time against
+/// `N`, exactly as they are for plain `[T; N]` arrays.
+///
+/// The only way to obtain a `Bounded` is through [`SliceWrapper::prove_len`] or
+/// [`SliceWrapper::expect_len`], which perform the length check exactly once. Because the
+/// backing storage is moved into `Bounded` (rather than re-borrowed), the proven length can
+/// never shrink out from under it.
+#[repr(transparent)]
+pub struct Bounded<'a, I, const N: usize, S>(
+    /// The actual data reference, proven to have at least `N` elements.
+    S,
+
+    /// Informs the compiler that the lifetime 'a is actually part of the type.
+    PhantomData<&'a ()>,
+
+    /// Informs the compiler that the type parameter I is actually part of the type.
+    PhantomData<I>,
+);
+
+impl<'a, I, T> SliceWrapper<'a, I, T>
+where
+    T: AsRef<[I]>,
+{
+    /// Checks that the backing slice has at least `N` elements, and if so, returns a
+    /// [`Bounded`] wrapper that can be indexed without any further runtime bounds checks.
+    pub fn prove_len<const N: usize>(self) -> Option<Bounded<'a, I, N, T>> {
+        if self.0.as_ref().len() >= N {
+            Some(Bounded(self.0, PhantomData, PhantomData))
+        } else {
+            None
+        }
+    }
+
+    /// Like [`SliceWrapper::prove_len`], but panics instead of returning `None` if the backing
+    /// slice has fewer than `N` elements.
+    pub fn expect_len<const N: usize>(self) -> Bounded<'a, I, N, T> {
+        self.prove_len::<N>()
+            .unwrap_or_else(|| panic!("Backing slice has fewer than {} elements", N))
+    }
+
+    /// Splits the wrapped slice into a `MID`-element head and a `REST`-element tail, checking
+    /// at runtime that the backing slice has room for both.
+    pub fn static_split_at<const MID: usize, const REST: usize>(&self) -> (&[I; MID], &[I; REST]) {
+        let inner: &[I] = self.0.as_ref();
+
+        assert!(inner.len() >= MID, "Split point {} is out of bounds", MID);
+        assert!(
+            inner.len() - MID >= REST,
+            "Not enough items after split point {} (requested {}; length: {})",
+            MID,
+            REST,
+            inner.len()
+        );
+
+        // SAFETY: We've verified bounds at runtime.
+        unsafe {
+            (
+                &*(inner.as_ptr() as *const [I; MID]),
+                &*(inner.as_ptr().add(MID) as *const [I; REST]),
+            )
+        }
+    }
+}
+
+impl<'a, I, T> SliceWrapper<'a, I, T>
+where
+    T: AsRef<[I]> + AsMut<[I]>,
+{
+    /// Mutable counterpart to [`SliceWrapper::static_split_at`].
+    pub fn static_split_at_mut<const MID: usize, const REST: usize>(
+        &mut self,
+    ) -> (&mut [I; MID], &mut [I; REST]) {
+        let inner: &mut [I] = self.0.as_mut();
+
+        assert!(inner.len() >= MID, "Split point {} is out of bounds", MID);
+        assert!(
+            inner.len() - MID >= REST,
+            "Not enough items after split point {} (requested {}; length: {})",
+            MID,
+            REST,
+            inner.len()
+        );
+
+        // SAFETY: We've verified bounds at runtime.
+        unsafe {
+            (
+                &mut *(inner.as_mut_ptr() as *mut [I; MID]),
+                &mut *(inner.as_mut_ptr().add(MID) as *mut [I; REST]),
+            )
+        }
+    }
+
+    /// Swaps the elements of the wrapped slice at `A` and `B`, checking at runtime that both
+    /// are in bounds.
+    pub fn static_swap<const A: usize, const B: usize>(&mut self) {
+        let inner: &mut [I] = self.0.as_mut();
+
+        assert!(inner.len() > A, "Index {} is out of bounds", A);
+        assert!(inner.len() > B, "Index {} is out of bounds", B);
+
+        let ptr = inner.as_mut_ptr();
+
+        // SAFETY: We've verified bounds at runtime, and `core::ptr::swap` tolerates `A == B`.
+        unsafe {
+            core::ptr::swap(ptr.add(A), ptr.add(B));
+        }
+    }
+}
+
+impl<const INDEX: usize, const N: usize, I, S: AsRef<[I]>> Index<StaticIndex<INDEX>>
+    for Bounded<'_, I, N, S>
+{
+    type Output = I;
+
+    fn index(&self, _: StaticIndex<INDEX>) -> &Self::Output {
+        let _ = <[I; N] as IsValidIndex<INDEX>>::RESULT;
+
+        // SAFETY: We've verified bounds at compile time against the proven length `N`, and
+        //         `Bounded` guarantees the backing slice has at least `N` elements.
+        unsafe { &*(self.0.as_ref().as_ptr().add(INDEX)) }
+    }
+}
+
+impl<const INDEX: usize, const N: usize, I, S: AsRef<[I]> + AsMut<[I]>> IndexMut<StaticIndex<INDEX>>
+    for Bounded<'_, I, N, S>
+{
+    fn index_mut(&mut self, _: StaticIndex<INDEX>) -> &mut Self::Output {
+        let _ = <[I; N] as IsValidIndex<INDEX>>::RESULT;
+
+        // SAFETY: We've verified bounds at compile time against the proven length `N`, and
+        //         `Bounded` guarantees the backing slice has at least `N` elements.
+        unsafe { &mut *(self.0.as_mut().as_mut_ptr().add(INDEX)) }
+    }
+}
+
+impl<const START: usize, const LENGTH: usize, const N: usize, I, S: AsRef<[I]>>
+    Index<StaticRangeIndex<START, LENGTH>> for Bounded<'_, I, N, S>
+{
+    type Output = [I; LENGTH];
+
+    fn index(&self, _: StaticRangeIndex<START, LENGTH>) -> &Self::Output {
+        let _ = <[I; N] as IsValidIndexRange<START, LENGTH>>::RESULT;
+
+        // SAFETY: We've verified bounds at compile time against the proven length `N`, and
+        //         `Bounded` guarantees the backing slice has at least `N` elements.
+        unsafe { &*(self.0.as_ref().as_ptr().add(START) as *const [I; LENGTH]) }
+    }
+}
+
+impl<const START: usize, const LENGTH: usize, const N: usize, I, S: AsRef<[I]> + AsMut<[I]>>
+    IndexMut<StaticRangeIndex<START, LENGTH>> for Bounded<'_, I, N, S>
+{
+    fn index_mut(&mut self, _: StaticRangeIndex<START, LENGTH>) -> &mut Self::Output {
+        let _ = <[I; N] as IsValidIndexRange<START, LENGTH>>::RESULT;
+
+        // SAFETY: We've verified bounds at compile time against the proven length `N`, and
+        //         `Bounded` guarantees the backing slice has at least `N` elements.
+        unsafe { &mut *(self.0.as_mut().as_mut_ptr().add(START) as *mut [I; LENGTH]) }
+    }
+}
+
 /// Fixed-size collections supporting copies from other fixed-size collections.
 ///
 /// # Examples
@@ -324,6 +670,41 @@ mod tests {
             arr[StaticIndex::<4>] = 6;
             assert_eq!(arr, [1, 2, 3, 4, 6]);
         }
+
+        #[test]
+        fn test_static_split_at() {
+            let arr = [1, 2, 3, 4, 5];
+            let (head, tail) = arr.static_split_at::<2, 3>();
+
+            assert_eq!(head, &[1, 2]);
+            assert_eq!(tail, &[3, 4, 5]);
+        }
+
+        #[test]
+        fn test_static_split_at_mut() {
+            let mut arr = [1, 2, 3, 4, 5];
+            let (head, tail) = arr.static_split_at_mut::<2, 3>();
+
+            head[0] = 10;
+            tail[0] = 20;
+            assert_eq!(arr, [10, 2, 20, 4, 5]);
+        }
+
+        #[test]
+        fn test_static_swap() {
+            let mut arr = [1, 2, 3, 4, 5];
+            arr.static_swap::<1, 3>();
+
+            assert_eq!(arr, [1, 4, 3, 2, 5]);
+        }
+
+        #[test]
+        fn test_static_swap_same_index() {
+            let mut arr = [1, 2, 3, 4, 5];
+            arr.static_swap::<2, 2>();
+
+            assert_eq!(arr, [1, 2, 3, 4, 5]);
+        }
     }
 
     mod wrapper_functionality {
@@ -373,6 +754,163 @@ mod tests {
             assert_eq!(y[StaticRangeIndex::<0, 3>], [1, 4, 5]);
             assert_eq!(x[0..3], [1, 4, 5]);
         }
+
+        #[test]
+        fn test_wrapped_static_split_at() {
+            let x = SliceWrapper::new(&[1, 2, 3, 4, 5]);
+            let (head, tail) = x.static_split_at::<2, 3>();
+
+            assert_eq!(head, &[1, 2]);
+            assert_eq!(tail, &[3, 4, 5]);
+        }
+
+        #[test]
+        fn test_wrapped_static_split_at_mut() {
+            let mut x = [1, 2, 3, 4, 5];
+            let mut y = SliceWrapper::new(&mut x);
+            let (head, tail) = y.static_split_at_mut::<2, 3>();
+
+            head[0] = 10;
+            tail[0] = 20;
+            assert_eq!(x, [10, 2, 20, 4, 5]);
+        }
+
+        #[test]
+        fn test_wrapped_static_swap() {
+            let mut x = [1, 2, 3, 4, 5];
+            let mut y = SliceWrapper::new(&mut x);
+            y.static_swap::<1, 3>();
+
+            assert_eq!(x, [1, 4, 3, 2, 5]);
+        }
+
+        #[test]
+        fn test_wrapped_slice_get_in_bounds() {
+            let x = SliceWrapper::new(&[1, 2, 3]);
+            assert_eq!(x.get::<2>(), Some(&3));
+        }
+
+        #[test]
+        fn test_wrapped_slice_get_out_of_bounds() {
+            let x = SliceWrapper::new(&[1, 2, 3]);
+            assert_eq!(x.get::<3>(), None);
+        }
+
+        #[test]
+        fn test_wrapped_slice_get_mut() {
+            let mut x = [1, 2, 3];
+            let mut y = SliceWrapper::new(&mut x);
+            *y.get_mut::<2>().unwrap() = 5;
+
+            assert_eq!(x[2], 5);
+        }
+
+        #[test]
+        fn test_wrapped_slice_get_range_in_bounds() {
+            let x = SliceWrapper::new(&[1, 2, 3]);
+            assert_eq!(x.get_range::<0, 2>(), Some(&[1, 2]));
+        }
+
+        #[test]
+        fn test_wrapped_slice_get_range_out_of_bounds() {
+            let x = SliceWrapper::new(&[1, 2, 3]);
+            assert_eq!(x.get_range::<0, 5>(), None);
+        }
+
+        #[test]
+        fn test_wrapped_slice_get_range_mut() {
+            let mut x = [1, 2, 3];
+            let mut y = SliceWrapper::new(&mut x);
+            y.get_range_mut::<0, 2>().unwrap().copy_from([4, 5]);
+
+            assert_eq!(x, [4, 5, 3]);
+        }
+    }
+
+    mod const_functionality {
+        use super::*;
+
+        #[test]
+        fn test_static_get() {
+            const ARR: [i32; 5] = [1, 2, 3, 4, 5];
+            const VALUE: i32 = *static_get::<2, 5, i32>(&ARR);
+
+            assert_eq!(VALUE, 3);
+        }
+
+        #[test]
+        fn test_static_get_range() {
+            const ARR: [i32; 5] = [1, 2, 3, 4, 5];
+            const SUB_ARR: [i32; 2] = *static_get_range::<1, 2, 5, i32>(&ARR);
+
+            assert_eq!(SUB_ARR, [2, 3]);
+        }
+
+        #[test]
+        fn test_static_get_mut() {
+            let mut arr = [1, 2, 3, 4, 5];
+            *static_get_mut::<2, 5, i32>(&mut arr) = 10;
+
+            assert_eq!(arr, [1, 2, 10, 4, 5]);
+        }
+
+        #[test]
+        fn test_static_get_range_mut() {
+            let mut arr = [1, 2, 3, 4, 5];
+            static_get_range_mut::<1, 2, 5, i32>(&mut arr).copy_from([20, 30]);
+
+            assert_eq!(arr, [1, 20, 30, 4, 5]);
+        }
+    }
+
+    mod bounded_functionality {
+        use super::*;
+
+        #[test]
+        fn test_prove_len_success() {
+            let x = SliceWrapper::new(&[1, 2, 3]).prove_len::<3>();
+            assert!(x.is_some());
+        }
+
+        #[test]
+        fn test_prove_len_failure() {
+            let x = SliceWrapper::new(&[1, 2, 3]).prove_len::<4>();
+            assert!(x.is_none());
+        }
+
+        #[test]
+        fn test_bounded_read_single() {
+            let x = SliceWrapper::new(&[1, 2, 3]).expect_len::<3>();
+            assert_eq!(x[StaticIndex::<2>], 3);
+        }
+
+        #[test]
+        fn test_bounded_write_single() {
+            let mut x = [1, 2, 3];
+            let mut y = SliceWrapper::new(&mut x).expect_len::<3>();
+            y[StaticIndex::<2>] = 5;
+            assert_eq!(x[2], 5);
+        }
+
+        #[test]
+        fn test_bounded_read_multi() {
+            let x = SliceWrapper::new(&[1, 2, 3]).expect_len::<3>();
+            assert_eq!(x[StaticRangeIndex::<0, 2>], [1, 2]);
+        }
+
+        #[test]
+        fn test_bounded_write_multi() {
+            let mut x = [1, 2, 3];
+            let mut y = SliceWrapper::new(&mut x).expect_len::<3>();
+            y[StaticRangeIndex::<0, 2>] = [3, 4];
+            assert_eq!(x, [3, 4, 3]);
+        }
+
+        #[test]
+        #[should_panic]
+        fn test_expect_len_panics_when_too_short() {
+            let _ = SliceWrapper::new(&[1, 2, 3]).expect_len::<4>();
+        }
     }
 
     mod wrapper_safety {
@@ -435,5 +973,20 @@ mod tests {
             let mut x = SliceWrapper::new(vec![1, 2, 3]);
             x[StaticRangeIndex::<0, 5>] = [2, 3, 4, 5, 6];
         }
+
+        #[test]
+        #[should_panic]
+        fn wrapped_slice_oob_split_should_panic() {
+            let x = SliceWrapper::new(&[1, 2, 3]);
+            let _ = x.static_split_at::<2, 5>();
+        }
+
+        #[test]
+        #[should_panic]
+        fn wrapped_slice_oob_swap_should_panic() {
+            let mut x = [1, 2, 3];
+            let mut x = SliceWrapper::new(&mut x);
+            x.static_swap::<0, 3>();
+        }
     }
 }
\ No newline at end of file